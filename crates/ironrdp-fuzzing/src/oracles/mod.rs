@@ -68,8 +68,8 @@ pub fn pdu_decode(data: &[u8]) {
     let _ = codecs::rfx::ChannelsPdu::from_buffer(data);
     let _ = codecs::rfx::RfxChannel::from_buffer(data);
 
-    let _ = input::InputEventPdu::from_buffer(data);
-    let _ = input::InputEvent::from_buffer(data);
+    let _ = decode::<input::InputEventPdu>(data);
+    let _ = decode::<input::InputEvent>(data);
 
     let _ = decode::<bitmap::rdp6::BitmapStream<'_>>(data);
 
@@ -1,11 +1,8 @@
-use std::io;
-
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::{FromPrimitive, ToPrimitive};
-use thiserror::Error;
 
-use crate::PduParsing;
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::{cast_length, ensure_fixed_part_size, invalid_message_err, Decode, Encode, PduResult};
 
 pub mod fast_path;
 pub mod mouse;
@@ -27,33 +24,45 @@ pub use self::unused::UnusedPdu;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InputEventPdu(pub Vec<InputEvent>);
 
-impl PduParsing for InputEventPdu {
-    type Error = InputEventError;
+impl InputEventPdu {
+    const FIXED_PART_SIZE: usize = 2 /* numEvents */ + 2 /* pad2Octets */;
+}
+
+impl<'de> Decode<'de> for InputEventPdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
 
-    fn from_buffer(mut stream: impl io::Read) -> Result<Self, Self::Error> {
-        let number_of_events = stream.read_u16::<LittleEndian>()?;
-        let _padding = stream.read_u16::<LittleEndian>()?;
+        let number_of_events = src.read_u16();
+        let _padding = src.read_u16();
 
         let events = (0..number_of_events)
-            .map(|_| InputEvent::from_buffer(&mut stream))
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|_| InputEvent::decode(src))
+            .collect::<PduResult<Vec<_>>>()?;
 
         Ok(Self(events))
     }
+}
 
-    fn to_buffer(&self, mut stream: impl io::Write) -> Result<(), Self::Error> {
-        stream.write_u16::<LittleEndian>(self.0.len() as u16)?;
-        stream.write_u16::<LittleEndian>(0)?; // padding
+impl Encode for InputEventPdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u16(cast_length!("InputEventPdu", "numEvents", self.0.len())?);
+        dst.write_u16(0); // padding
 
         for event in self.0.iter() {
-            event.to_buffer(&mut stream)?;
+            event.encode(dst)?;
         }
 
         Ok(())
     }
 
-    fn buffer_length(&self) -> usize {
-        4 + self.0.iter().map(PduParsing::buffer_length).sum::<usize>()
+    fn name(&self) -> &'static str {
+        "InputEventPdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE + self.0.iter().map(Encode::size).sum::<usize>()
     }
 }
 
@@ -68,51 +77,64 @@ pub enum InputEvent {
     MouseRel(MouseRelPdu),
 }
 
-impl PduParsing for InputEvent {
-    type Error = InputEventError;
+impl InputEvent {
+    const FIXED_PART_SIZE: usize = 4 /* eventTime */ + 2 /* messageType */;
+}
+
+impl<'de> Decode<'de> for InputEvent {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
 
-    fn from_buffer(mut stream: impl io::Read) -> Result<Self, Self::Error> {
-        let _event_time = stream.read_u32::<LittleEndian>()?; // ignored by a server
-        let event_type = stream.read_u16::<LittleEndian>()?;
-        let event_type =
-            InputEventType::from_u16(event_type).ok_or(InputEventError::InvalidInputEventType(event_type))?;
+        let _event_time = src.read_u32(); // ignored by a server
+        let event_type = src.read_u16();
+        let event_type = InputEventType::from_u16(event_type)
+            .ok_or_else(|| invalid_message_err!("messageType", "invalid input event type"))?;
 
         match event_type {
-            InputEventType::Sync => Ok(Self::Sync(SyncPdu::from_buffer(&mut stream)?)),
-            InputEventType::Unused => Ok(Self::Unused(UnusedPdu::from_buffer(&mut stream)?)),
-            InputEventType::ScanCode => Ok(Self::ScanCode(ScanCodePdu::from_buffer(&mut stream)?)),
-            InputEventType::Unicode => Ok(Self::Unicode(UnicodePdu::from_buffer(&mut stream)?)),
-            InputEventType::Mouse => Ok(Self::Mouse(MousePdu::from_buffer(&mut stream)?)),
-            InputEventType::MouseX => Ok(Self::MouseX(MouseXPdu::from_buffer(&mut stream)?)),
-            InputEventType::MouseRel => Ok(Self::MouseRel(MouseRelPdu::from_buffer(&mut stream)?)),
+            InputEventType::Sync => Ok(Self::Sync(SyncPdu::decode(src)?)),
+            InputEventType::Unused => Ok(Self::Unused(UnusedPdu::decode(src)?)),
+            InputEventType::ScanCode => Ok(Self::ScanCode(ScanCodePdu::decode(src)?)),
+            InputEventType::Unicode => Ok(Self::Unicode(UnicodePdu::decode(src)?)),
+            InputEventType::Mouse => Ok(Self::Mouse(MousePdu::decode(src)?)),
+            InputEventType::MouseX => Ok(Self::MouseX(MouseXPdu::decode(src)?)),
+            InputEventType::MouseRel => Ok(Self::MouseRel(MouseRelPdu::decode(src)?)),
         }
     }
+}
 
-    fn to_buffer(&self, mut stream: impl io::Write) -> Result<(), Self::Error> {
-        stream.write_u32::<LittleEndian>(0)?; // event time is ignored by a server
-        stream.write_u16::<LittleEndian>(InputEventType::from(self).to_u16().unwrap())?;
+impl Encode for InputEvent {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u32(0); // event time is ignored by a server
+        dst.write_u16(InputEventType::from(self).to_u16().unwrap());
 
         match self {
-            Self::Sync(pdu) => pdu.to_buffer(&mut stream),
-            Self::Unused(pdu) => pdu.to_buffer(&mut stream),
-            Self::ScanCode(pdu) => pdu.to_buffer(&mut stream),
-            Self::Unicode(pdu) => pdu.to_buffer(&mut stream),
-            Self::Mouse(pdu) => pdu.to_buffer(&mut stream),
-            Self::MouseX(pdu) => pdu.to_buffer(&mut stream),
-            Self::MouseRel(pdu) => pdu.to_buffer(&mut stream),
+            Self::Sync(pdu) => pdu.encode(dst),
+            Self::Unused(pdu) => pdu.encode(dst),
+            Self::ScanCode(pdu) => pdu.encode(dst),
+            Self::Unicode(pdu) => pdu.encode(dst),
+            Self::Mouse(pdu) => pdu.encode(dst),
+            Self::MouseX(pdu) => pdu.encode(dst),
+            Self::MouseRel(pdu) => pdu.encode(dst),
         }
     }
 
-    fn buffer_length(&self) -> usize {
-        6 + match self {
-            Self::Sync(pdu) => pdu.buffer_length(),
-            Self::Unused(pdu) => pdu.buffer_length(),
-            Self::ScanCode(pdu) => pdu.buffer_length(),
-            Self::Unicode(pdu) => pdu.buffer_length(),
-            Self::Mouse(pdu) => pdu.buffer_length(),
-            Self::MouseX(pdu) => pdu.buffer_length(),
-            Self::MouseRel(pdu) => pdu.buffer_length(),
-        }
+    fn name(&self) -> &'static str {
+        "InputEvent"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+            + match self {
+                Self::Sync(pdu) => pdu.size(),
+                Self::Unused(pdu) => pdu.size(),
+                Self::ScanCode(pdu) => pdu.size(),
+                Self::Unicode(pdu) => pdu.size(),
+                Self::Mouse(pdu) => pdu.size(),
+                Self::MouseX(pdu) => pdu.size(),
+                Self::MouseRel(pdu) => pdu.size(),
+            }
     }
 }
 
@@ -141,21 +163,3 @@ impl From<&InputEvent> for InputEventType {
         }
     }
 }
-
-#[derive(Debug, Error)]
-pub enum InputEventError {
-    #[error("IO error")]
-    IOError(#[from] io::Error),
-    #[error("invalid Input Event type: {0}")]
-    InvalidInputEventType(u16),
-    #[error("encryption not supported")]
-    EncryptionNotSupported,
-    #[error("event code not supported {0}")]
-    EventCodeUnsupported(u8),
-    #[error("keyboard flags not supported {0}")]
-    KeyboardFlagsUnsupported(u8),
-    #[error("synchronize flags not supported {0}")]
-    SynchronizeFlagsUnsupported(u8),
-    #[error("Fast-Path Input Event PDU is empty")]
-    EmptyFastPathInput,
-}
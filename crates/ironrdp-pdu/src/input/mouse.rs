@@ -0,0 +1,66 @@
+use bitflags::bitflags;
+
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::{ensure_fixed_part_size, Decode, Encode, PduResult};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct PointerFlags: u16 {
+        const MOVE = 0x0800;
+        const DOWN = 0x8000;
+        const BUTTON1 = 0x1000;
+        const BUTTON2 = 0x2000;
+        const BUTTON3 = 0x4000;
+        const WHEEL = 0x0200;
+        const HWHEEL = 0x0400;
+        const WHEEL_NEGATIVE = 0x0100;
+        const WHEEL_ROTATION_MASK = 0x01FF;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MousePdu {
+    pub flags: PointerFlags,
+    pub x_position: u16,
+    pub y_position: u16,
+}
+
+impl MousePdu {
+    const FIXED_PART_SIZE: usize = 2 /* flags */ + 2 /* xPos */ + 2 /* yPos */;
+}
+
+impl<'de> Decode<'de> for MousePdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
+
+        let flags = PointerFlags::from_bits_retain(src.read_u16());
+        let x_position = src.read_u16();
+        let y_position = src.read_u16();
+
+        Ok(Self {
+            flags,
+            x_position,
+            y_position,
+        })
+    }
+}
+
+impl Encode for MousePdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u16(self.flags.bits());
+        dst.write_u16(self.x_position);
+        dst.write_u16(self.y_position);
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "MousePdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+    }
+}
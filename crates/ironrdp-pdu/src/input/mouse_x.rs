@@ -0,0 +1,60 @@
+use bitflags::bitflags;
+
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::{ensure_fixed_part_size, Decode, Encode, PduResult};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct PointerXFlags: u16 {
+        const DOWN = 0x8000;
+        const BUTTON1 = 0x0001;
+        const BUTTON2 = 0x0002;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseXPdu {
+    pub flags: PointerXFlags,
+    pub x_position: u16,
+    pub y_position: u16,
+}
+
+impl MouseXPdu {
+    const FIXED_PART_SIZE: usize = 2 /* flags */ + 2 /* xPos */ + 2 /* yPos */;
+}
+
+impl<'de> Decode<'de> for MouseXPdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
+
+        let flags = PointerXFlags::from_bits_retain(src.read_u16());
+        let x_position = src.read_u16();
+        let y_position = src.read_u16();
+
+        Ok(Self {
+            flags,
+            x_position,
+            y_position,
+        })
+    }
+}
+
+impl Encode for MouseXPdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u16(self.flags.bits());
+        dst.write_u16(self.x_position);
+        dst.write_u16(self.y_position);
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "MouseXPdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+    }
+}
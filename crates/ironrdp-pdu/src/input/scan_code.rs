@@ -0,0 +1,56 @@
+use bitflags::bitflags;
+
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::{ensure_fixed_part_size, Decode, Encode, PduResult};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct KeyboardFlags: u16 {
+        const EXTENDED = 0x0100;
+        const EXTENDED1 = 0x0200;
+        const DOWN = 0x4000;
+        const RELEASE = 0x8000;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanCodePdu {
+    pub flags: KeyboardFlags,
+    pub key_code: u16,
+}
+
+impl ScanCodePdu {
+    const FIXED_PART_SIZE: usize = 2 /* flags */ + 2 /* keyCode */ + 2 /* pad2Octets */;
+}
+
+impl<'de> Decode<'de> for ScanCodePdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
+
+        let flags = KeyboardFlags::from_bits_retain(src.read_u16());
+        let key_code = src.read_u16();
+        let _padding = src.read_u16();
+
+        Ok(Self { flags, key_code })
+    }
+}
+
+impl Encode for ScanCodePdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u16(self.flags.bits());
+        dst.write_u16(self.key_code);
+        dst.write_u16(0); // padding
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "ScanCodePdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+    }
+}
@@ -0,0 +1,53 @@
+use bitflags::bitflags;
+
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::{ensure_fixed_part_size, Decode, Encode, PduResult};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct SyncToggleFlags: u32 {
+        const SCROLL_LOCK = 0x0000_0001;
+        const NUM_LOCK = 0x0000_0002;
+        const CAPS_LOCK = 0x0000_0004;
+        const KANA_LOCK = 0x0000_0008;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncPdu {
+    pub toggle_flags: SyncToggleFlags,
+}
+
+impl SyncPdu {
+    const FIXED_PART_SIZE: usize = 2 /* pad2Octets */ + 4 /* toggleFlags */;
+}
+
+impl<'de> Decode<'de> for SyncPdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
+
+        let _padding = src.read_u16();
+        let toggle_flags = SyncToggleFlags::from_bits_retain(src.read_u32());
+
+        Ok(Self { toggle_flags })
+    }
+}
+
+impl Encode for SyncPdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u16(0); // padding
+        dst.write_u32(self.toggle_flags.bits());
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "SyncPdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+    }
+}
@@ -0,0 +1,45 @@
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::input::scan_code::KeyboardFlags;
+use crate::{ensure_fixed_part_size, Decode, Encode, PduResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodePdu {
+    pub flags: KeyboardFlags,
+    pub unicode_code: u16,
+}
+
+impl UnicodePdu {
+    const FIXED_PART_SIZE: usize = 2 /* flags */ + 2 /* unicodeCode */ + 2 /* pad2Octets */;
+}
+
+impl<'de> Decode<'de> for UnicodePdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
+
+        let flags = KeyboardFlags::from_bits_retain(src.read_u16());
+        let unicode_code = src.read_u16();
+        let _padding = src.read_u16();
+
+        Ok(Self { flags, unicode_code })
+    }
+}
+
+impl Encode for UnicodePdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u16(self.flags.bits());
+        dst.write_u16(self.unicode_code);
+        dst.write_u16(0); // padding
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "UnicodePdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+    }
+}
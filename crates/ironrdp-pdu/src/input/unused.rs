@@ -0,0 +1,39 @@
+use crate::cursor::{ReadCursor, WriteCursor};
+use crate::{ensure_fixed_part_size, Decode, Encode, PduResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnusedPdu;
+
+impl UnusedPdu {
+    const FIXED_PART_SIZE: usize = 4 /* pad4Octets */ + 2 /* pad2Octets */;
+}
+
+impl<'de> Decode<'de> for UnusedPdu {
+    fn decode(src: &mut ReadCursor<'de>) -> PduResult<Self> {
+        ensure_fixed_part_size!(in: src);
+
+        let _padding = src.read_u32();
+        let _padding = src.read_u16();
+
+        Ok(Self)
+    }
+}
+
+impl Encode for UnusedPdu {
+    fn encode(&self, dst: &mut WriteCursor<'_>) -> PduResult<()> {
+        ensure_fixed_part_size!(in: dst);
+
+        dst.write_u32(0); // padding
+        dst.write_u16(0); // padding
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "UnusedPdu"
+    }
+
+    fn size(&self) -> usize {
+        Self::FIXED_PART_SIZE
+    }
+}